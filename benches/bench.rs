@@ -42,6 +42,35 @@ fn benchmark(c: &mut Criterion) {
         );
     }
 
+    {
+        let mut lid = LID::<12, 8>::new();
+        c.bench_function("LID::generate_into w/ 12 prefix, 8 sequence", |b| {
+            let mut out = String::new();
+            b.iter(|| {
+                lid.generate_into(&mut out);
+                black_box(&out);
+            });
+        });
+    }
+
+    {
+        let mut lid = LID::<12, 8>::new();
+        c.bench_function("LID::generate_many(100) w/ 12 prefix, 8 sequence", |b| {
+            b.iter(|| black_box(lid.generate_many(100)));
+        });
+    }
+
+    {
+        let mut lid = LID::<12, 8>::new();
+        c.bench_function("LID::fill(100) w/ 12 prefix, 8 sequence", |b| {
+            let mut buf = vec![String::new(); 100];
+            b.iter(|| {
+                lid.fill(&mut buf);
+                black_box(&buf);
+            });
+        });
+    }
+
     c.bench_function("colorid w/ 20 bytes", |b| {
         b.iter(|| black_box(colorid::colorid(20)));
     });
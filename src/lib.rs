@@ -5,6 +5,10 @@
 //! The easiest way to use LID is by using the `easy` feature and using [`generate_distributed`] or [`generate_random`].
 //! These use a static [LID] instance backed by a [Mutex].
 //!
+//! In multi-threaded, high-throughput code, prefer the lock-free [`generate_distributed_local`] and
+//! [`generate_random_local`], which use a thread-local [LID] instance instead of contending on a
+//! shared [Mutex].
+//!
 //! You may also change the alphabet used by switching up the feature flags.
 //! The available features are: base32, base36, and base62.
 //! NOTE: When using base62, the default ID size will change to 20 bytes.
@@ -32,6 +36,36 @@
 //! println!("{:?}", lid.generate());
 //! ```
 //!
+//! By default, [LID] draws its randomness from [`OsRng`], but it is generic over any
+//! [`RngCore`] so you can plug in a fast, reproducible PRNG instead:
+//! ```
+//! use lid::LID;
+//! use rand::rngs::mock::StepRng;
+//!
+//! let mut lid = LID::<12, 8, 100, 1000, StepRng>::with_rng(StepRng::new(0, 1));
+//! println!("{:?}", lid.generate());
+//! ```
+//! [`LID::from_seed`] and [`LID::seed_from_u64`] are also available for any `R: SeedableRng`.
+//!
+//! You can also mint IDs from an alphabet chosen at runtime, rather than the feature-selected
+//! one, via [`Alphabet`] and [`LID::with_alphabet`]:
+//! ```
+//! use lid::{Alphabet, LID};
+//!
+//! let alphabet = Alphabet::new("0123456789").unwrap();
+//! let mut lid = LID::<12, 8>::with_alphabet(alphabet);
+//! println!("{:?}", lid.generate());
+//! ```
+//!
+//! For security-sensitive use cases, [`LID::with_reseed_after`] bounds how many IDs can share a
+//! prefix, regardless of how large `SEQUENCE_LENGTH` is:
+//! ```
+//! use lid::LID;
+//!
+//! let mut lid = LID::<6, 9, 1000, 1_000_000>::with_reseed_after(10_000);
+//! println!("{:?}", lid.generate());
+//! ```
+//!
 
 #![warn(clippy::pedantic)]
 #![allow(clippy::cast_possible_truncation)]
@@ -52,7 +86,7 @@ compile_error!("You must only have one of the alphabet related features enabled!
 use rand::{
     distributions::{Distribution, Uniform},
     rngs::OsRng,
-    Rng,
+    Rng, RngCore, SeedableRng,
 };
 
 pub mod configs {
@@ -72,6 +106,8 @@ pub mod configs {
 
 #[cfg(feature = "easy")]
 pub mod easy {
+    use std::cell::RefCell;
+
     use lazy_static::lazy_static;
     use spin::Mutex;
 
@@ -86,29 +122,67 @@ pub mod easy {
             Mutex::new(new_random());
     }
 
+    thread_local! {
+        static DISTRIBUTED_LOCAL: RefCell<LID> = RefCell::new(new_distributed());
+        static RANDOM_LOCAL: RefCell<LID<12, 8, 10_000_000, { u64::MAX }>> =
+            RefCell::new(new_random());
+    }
+
+    /// Generates an ID using a low-randomness [`LID`] instance shared across all threads behind a
+    /// [`Mutex`]. Prefer [`generate_distributed_local`] in multi-threaded, high-throughput code.
     #[must_use]
     #[cfg(not(feature = "no-unsafe"))]
     pub fn generate_distributed() -> String {
         DISTRIBUTED_INST.lock().generate()
     }
 
-    #[must_use]
     #[cfg(feature = "no-unsafe")]
     pub fn generate_distributed() -> Result<String, std::string::FromUtf8Error> {
         DISTRIBUTED_INST.lock().generate()
     }
 
+    /// Generates an ID using a high-randomness [`LID`] instance shared across all threads behind
+    /// a [`Mutex`]. Prefer [`generate_random_local`] in multi-threaded, high-throughput code.
     #[must_use]
     #[cfg(not(feature = "no-unsafe"))]
     pub fn generate_random() -> String {
         RANDOM_INST.lock().generate()
     }
 
-    #[must_use]
     #[cfg(feature = "no-unsafe")]
     pub fn generate_random() -> Result<String, std::string::FromUtf8Error> {
         RANDOM_INST.lock().generate()
     }
+
+    /// Generates an ID using a low-randomness [`LID`] instance local to the calling thread.
+    ///
+    /// Each thread is seeded independently from [`OsRng`](rand::rngs::OsRng), so distinct
+    /// threads draw distinct random prefixes and collision safety is preserved without any
+    /// locking. This is the high-throughput path for multi-threaded servers; use
+    /// [`generate_distributed`] instead if you need every call to draw from one shared sequence.
+    #[must_use]
+    #[cfg(not(feature = "no-unsafe"))]
+    pub fn generate_distributed_local() -> String {
+        DISTRIBUTED_LOCAL.with(|lid| lid.borrow_mut().generate())
+    }
+
+    #[cfg(feature = "no-unsafe")]
+    pub fn generate_distributed_local() -> Result<String, std::string::FromUtf8Error> {
+        DISTRIBUTED_LOCAL.with(|lid| lid.borrow_mut().generate())
+    }
+
+    /// Generates an ID using a high-randomness [`LID`] instance local to the calling thread. See
+    /// [`generate_distributed_local`] for why this needs no locking.
+    #[must_use]
+    #[cfg(not(feature = "no-unsafe"))]
+    pub fn generate_random_local() -> String {
+        RANDOM_LOCAL.with(|lid| lid.borrow_mut().generate())
+    }
+
+    #[cfg(feature = "no-unsafe")]
+    pub fn generate_random_local() -> Result<String, std::string::FromUtf8Error> {
+        RANDOM_LOCAL.with(|lid| lid.borrow_mut().generate())
+    }
 }
 
 #[cfg(feature = "base32")]
@@ -121,7 +195,98 @@ pub const BASE_ALPHABET: &[u8] = "ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890".as_bytes
 pub const BASE_ALPHABET: &[u8] =
     "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890".as_bytes();
 
-const BASE: u64 = BASE_ALPHABET.len() as u64;
+/// A validated, runtime-configurable alphabet used to render prefixes and sequences.
+///
+/// The `base32`/`base36`/`base62` feature flags pick a compile-time alphabet, but a single
+/// process can only use one of them. An [`Alphabet`] lets you bypass that restriction: build one
+/// from any byte slice and pass it to [`LID::with_alphabet`] to mint IDs in a different encoding,
+/// side by side with the feature-selected default.
+///
+/// Symbols are sampled independently, one byte per position, so an alphabet can only ever contain
+/// ASCII bytes: a non-ASCII byte has no meaning on its own, and pairing up multi-byte UTF-8
+/// sequences across independently-drawn positions can't be guaranteed. [`Alphabet::new`] rejects
+/// anything else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    bytes: Vec<u8>,
+}
+
+/// Error returned by [`Alphabet::new`] when the supplied bytes can't form a valid alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// The alphabet did not contain any bytes.
+    Empty,
+    /// `byte` appeared more than once, first re-appearing at `index`.
+    DuplicateByte { byte: u8, index: usize },
+    /// `byte` at `index` is not ASCII. Alphabet symbols are sampled independently, byte by byte,
+    /// so only ASCII bytes can be assembled into valid UTF-8 this way.
+    NonAsciiByte { byte: u8, index: usize },
+}
+
+impl std::fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "alphabet must contain at least one byte"),
+            Self::DuplicateByte { byte, index } => {
+                write!(f, "alphabet byte {byte:#04x} at index {index} is a duplicate")
+            }
+            Self::NonAsciiByte { byte, index } => {
+                write!(f, "alphabet byte {byte:#04x} at index {index} is not ASCII")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+impl Alphabet {
+    /// Validates `bytes` and wraps them as an [`Alphabet`].
+    ///
+    /// # Errors
+    /// Returns [`AlphabetError::Empty`] if `bytes` is empty, [`AlphabetError::NonAsciiByte`] if
+    /// any byte is not ASCII, or [`AlphabetError::DuplicateByte`] if the same byte appears more
+    /// than once.
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Result<Self, AlphabetError> {
+        let bytes = bytes.into();
+        if bytes.is_empty() {
+            return Err(AlphabetError::Empty);
+        }
+
+        let mut seen = [false; 256];
+        for (index, &byte) in bytes.iter().enumerate() {
+            if !byte.is_ascii() {
+                return Err(AlphabetError::NonAsciiByte { byte, index });
+            }
+            if seen[byte as usize] {
+                return Err(AlphabetError::DuplicateByte { byte, index });
+            }
+            seen[byte as usize] = true;
+        }
+
+        Ok(Self { bytes })
+    }
+
+    /// The number of symbols in this alphabet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Always `false`: [`Alphabet::new`] rejects empty byte slices.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+fn default_alphabet() -> Alphabet {
+    Alphabet::new(BASE_ALPHABET).expect("the built-in BASE_ALPHABET is always a valid alphabet")
+}
 
 // Base62 has to have a smaller default length because MAX_SEQUENCE is too big otherwise.
 #[cfg(feature = "base62")]
@@ -130,26 +295,45 @@ pub struct LID<
     const SEQUENCE_LENGTH: usize = 8,
     const MIN_INCREMENT: u64 = 100,
     const MAX_INCREMENT: u64 = 1000,
+    R = OsRng,
 > {
+    alphabet: Alphabet,
+    base: u64,
+    max_sequence: u64,
     prefix: Vec<u8>,
     sequence: u64,
     increment: u64,
     inner_buffer: Vec<u8>,
+    rng: R,
+    reseed_after: Option<u64>,
+    since_reseed: u64,
 }
 
 #[cfg(not(feature = "base62"))]
 /// The combined total of `PREFIX_LENGTH` and `SEQUENCE_LENGTH` is the length of the ID.
 /// By default, this is 28 bytes.
+///
+/// `R` is the random number generator backing prefix and increment generation. It defaults to
+/// [`OsRng`], which draws from the operating system's entropy source on every reseed. Swap in any
+/// [`RngCore`] (and [`SeedableRng`] for the seeded constructors) to get a reproducible ID stream
+/// or to avoid the OS syscall on throughput-sensitive paths.
 pub struct LID<
     const PREFIX_LENGTH: usize = 16,
     const SEQUENCE_LENGTH: usize = 12,
     const MIN_INCREMENT: u64 = 100,
     const MAX_INCREMENT: u64 = 1000,
+    R = OsRng,
 > {
+    alphabet: Alphabet,
+    base: u64,
+    max_sequence: u64,
     prefix: Vec<u8>,
     sequence: u64,
     increment: u64,
     inner_buffer: Vec<u8>,
+    rng: R,
+    reseed_after: Option<u64>,
+    since_reseed: u64,
 }
 
 impl<
@@ -157,70 +341,316 @@ impl<
         const SEQUENCE_LENGTH: usize,
         const MIN_INCREMENT: u64,
         const MAX_INCREMENT: u64,
-    > LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT>
+        R: RngCore,
+    > LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R>
 {
-    const MAX_SEQUENCE: u64 = BASE.pow(SEQUENCE_LENGTH as u32);
     const ID_LENGTH: usize = PREFIX_LENGTH + SEQUENCE_LENGTH;
 
-    #[must_use]
-    pub fn new() -> Self {
+    fn build(alphabet: Alphabet, rng: R, reseed_after: Option<u64>) -> Self {
+        let base = alphabet.len() as u64;
+        let max_sequence = base.pow(SEQUENCE_LENGTH as u32);
         let mut lid = Self {
+            alphabet,
+            base,
+            max_sequence,
             prefix: vec![0; PREFIX_LENGTH],
             sequence: 0,
             increment: 0,
             inner_buffer: vec![0; Self::ID_LENGTH],
+            rng,
+            reseed_after,
+            since_reseed: 0,
         };
         lid.reset();
         lid.new_prefix();
+        lid.inner_buffer[..PREFIX_LENGTH].copy_from_slice(&lid.prefix);
         lid
     }
 
+    /// Creates a new [`LID`] backed by an already-constructed RNG, e.g. a seeded [`Pcg64`] or
+    /// [`ChaCha8Rng`] from the `rand` ecosystem.
+    ///
+    /// [`Pcg64`]: https://docs.rs/rand_pcg
+    /// [`ChaCha8Rng`]: https://docs.rs/rand_chacha
+    #[must_use]
+    pub fn with_rng(rng: R) -> Self {
+        Self::build(default_alphabet(), rng, None)
+    }
+
     fn reset(&mut self) {
-        self.sequence = OsRng.gen_range(0..Self::MAX_SEQUENCE);
-        self.increment = OsRng.gen_range(MIN_INCREMENT..MAX_INCREMENT);
+        self.sequence = self.rng.gen_range(0..self.max_sequence);
+        self.increment = self.rng.gen_range(MIN_INCREMENT..MAX_INCREMENT);
     }
 
     fn new_prefix(&mut self) {
-        let between = Uniform::from(0..BASE);
-        for i in 0..PREFIX_LENGTH {
-            self.prefix[i] = BASE_ALPHABET[between.sample(&mut OsRng) as usize];
+        let between = Uniform::from(0..self.alphabet.len());
+        let bytes = self.alphabet.bytes();
+        for (slot, index) in self.prefix.iter_mut().zip(between.sample_iter(&mut self.rng)) {
+            *slot = bytes[index];
         }
     }
 
-    fn new_sequence(&mut self) {
-        self.sequence = (self.sequence + self.increment) % Self::MAX_SEQUENCE;
+    /// Forces a new prefix and increment, as though the automatic reseed threshold (see
+    /// [`LID::with_reseed_after`]) had just been reached, and resets its countdown.
+    ///
+    /// Useful for security-sensitive callers that want to bound prediction windows around a known
+    /// event (e.g. a suspected key compromise) without waiting for the next sequence wrap or
+    /// automatic reseed.
+    pub fn reseed(&mut self) {
+        self.new_prefix();
+        self.increment = self.rng.gen_range(MIN_INCREMENT..MAX_INCREMENT);
+        self.inner_buffer[..PREFIX_LENGTH].copy_from_slice(&self.prefix);
+        self.since_reseed = 0;
+    }
+
+    /// Advances the sequence, returning `true` if it wrapped and drew a new prefix.
+    fn new_sequence(&mut self) -> bool {
+        self.sequence = (self.sequence + self.increment) % self.max_sequence;
         if self.sequence == 0 {
             self.new_prefix();
+            true
+        } else {
+            false
         }
     }
 
-    fn copy_sequence_into(buffer: &mut [u8], mut sequence: u64) {
+    fn copy_sequence_into(buffer: &mut [u8], mut sequence: u64, base: u64, alphabet: &[u8]) {
         for digit in buffer.iter_mut().rev() {
-            *digit = BASE_ALPHABET[(sequence % BASE) as usize];
-            sequence /= BASE;
+            *digit = alphabet[(sequence % base) as usize];
+            sequence /= base;
         }
     }
 
+    /// Advances the sequence and rewrites `inner_buffer`. The prefix bytes are only touched when
+    /// the sequence wraps, or when the automatic reseed threshold from [`LID::with_reseed_after`]
+    /// is reached independent of the wrap condition, rather than being recopied on every call.
+    fn write_id(&mut self) {
+        let wrapped = self.new_sequence();
+        self.since_reseed += 1;
+
+        if wrapped {
+            self.inner_buffer[..PREFIX_LENGTH].copy_from_slice(&self.prefix);
+            self.since_reseed = 0;
+        } else if self.reseed_after.is_some_and(|after| self.since_reseed >= after) {
+            self.reseed();
+        }
+
+        Self::copy_sequence_into(
+            &mut self.inner_buffer[PREFIX_LENGTH..],
+            self.sequence,
+            self.base,
+            self.alphabet.bytes(),
+        );
+    }
+
     /// Generates a new ID.
     #[cfg(not(feature = "no-unsafe"))]
     pub fn generate(&mut self) -> String {
-        self.new_sequence();
-        self.inner_buffer[..PREFIX_LENGTH].copy_from_slice(&self.prefix);
-        Self::copy_sequence_into(&mut self.inner_buffer[PREFIX_LENGTH..], self.sequence);
+        self.write_id();
 
-        // Safety: The alphabet used ensures that the bytes are valid UTF-8.
+        // Safety: `Alphabet::new` rejects non-ASCII bytes, so any arrangement of its bytes is
+        // valid UTF-8.
         unsafe { String::from_utf8_unchecked(self.inner_buffer.clone()) }
     }
 
     /// Generates a new ID.
     #[cfg(feature = "no-unsafe")]
     pub fn generate(&mut self) -> Result<String, std::string::FromUtf8Error> {
-        self.new_sequence();
-        self.inner_buffer[..PREFIX_LENGTH].copy_from_slice(&self.prefix);
-        Self::copy_sequence_into(&mut self.inner_buffer[PREFIX_LENGTH..], self.sequence);
-
+        self.write_id();
         String::from_utf8(self.inner_buffer.clone())
     }
+
+    /// Generates a new ID into `out`, reusing its existing allocation instead of returning a
+    /// fresh [`String`] — the zero-allocation counterpart to [`LID::generate`] for callers minting
+    /// IDs in bulk. See [`LID::fill`] to do this over a whole slice.
+    #[cfg(not(feature = "no-unsafe"))]
+    pub fn generate_into(&mut self, out: &mut String) {
+        self.write_id();
+        out.clear();
+
+        // Safety: `Alphabet::new` rejects non-ASCII bytes, so any arrangement of its bytes is
+        // valid UTF-8.
+        unsafe { out.as_mut_vec().extend_from_slice(&self.inner_buffer) }
+    }
+
+    /// Generates a new ID into `out`, reusing its existing allocation instead of returning a
+    /// fresh [`String`] — the zero-allocation counterpart to [`LID::generate`] for callers minting
+    /// IDs in bulk. See [`LID::fill`] to do this over a whole slice.
+    #[cfg(feature = "no-unsafe")]
+    pub fn generate_into(&mut self, out: &mut String) -> Result<(), std::str::Utf8Error> {
+        self.write_id();
+        let id = std::str::from_utf8(&self.inner_buffer)?;
+        out.clear();
+        out.push_str(id);
+        Ok(())
+    }
+
+    /// Generates `n` new IDs. Prefer [`LID::fill`] if you can reuse an existing buffer of
+    /// [`String`]s across calls, since this allocates a fresh [`Vec`] per call and a [`String`]
+    /// per ID.
+    #[must_use]
+    #[cfg(not(feature = "no-unsafe"))]
+    pub fn generate_many(&mut self, n: usize) -> Vec<String> {
+        let mut ids = Vec::with_capacity(n);
+        for _ in 0..n {
+            ids.push(self.generate());
+        }
+        ids
+    }
+
+    /// Generates `n` new IDs. Prefer [`LID::fill`] if you can reuse an existing buffer of
+    /// [`String`]s across calls, since this allocates a fresh [`Vec`] per call.
+    #[must_use]
+    #[cfg(feature = "no-unsafe")]
+    pub fn generate_many(&mut self, n: usize) -> Vec<Result<String, std::string::FromUtf8Error>> {
+        let mut ids = Vec::with_capacity(n);
+        for _ in 0..n {
+            ids.push(self.generate());
+        }
+        ids
+    }
+
+    /// Fills `buf` with newly generated IDs, reusing each [`String`]'s existing allocation via
+    /// [`LID::generate_into`] instead of allocating `buf.len()` new ones. This is the preferred
+    /// way to mint IDs in bulk, e.g. for a database loader inserting millions of rows.
+    #[cfg(not(feature = "no-unsafe"))]
+    pub fn fill(&mut self, buf: &mut [String]) {
+        for out in buf {
+            self.generate_into(out);
+        }
+    }
+
+    /// Fills `buf` with newly generated IDs, reusing each [`String`]'s existing allocation via
+    /// [`LID::generate_into`] instead of allocating `buf.len()` new ones. This is the preferred
+    /// way to mint IDs in bulk, e.g. for a database loader inserting millions of rows.
+    #[cfg(feature = "no-unsafe")]
+    pub fn fill(&mut self, buf: &mut [String]) -> Result<(), std::str::Utf8Error> {
+        for out in buf {
+            self.generate_into(out)?;
+        }
+        Ok(())
+    }
+}
+
+impl<
+        const PREFIX_LENGTH: usize,
+        const SEQUENCE_LENGTH: usize,
+        const MIN_INCREMENT: u64,
+        const MAX_INCREMENT: u64,
+        R: RngCore + Default,
+    > LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R>
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_rng(R::default())
+    }
+
+    /// Creates a new [`LID`] that draws its prefix and sequence characters from a custom
+    /// [`Alphabet`] instead of the feature-selected [`BASE_ALPHABET`].
+    #[must_use]
+    pub fn with_alphabet(alphabet: Alphabet) -> Self {
+        Self::build(alphabet, R::default(), None)
+    }
+
+    /// Creates a new [`LID`] that automatically forces a fresh prefix and increment every
+    /// `reseed_after` generated IDs, independent of the sequence wrap condition.
+    ///
+    /// By default a prefix only changes when the sequence wraps, so with a large
+    /// `SEQUENCE_LENGTH` a single prefix can persist across an enormous run of IDs, widening the
+    /// window in which an observer who has guessed the increment can predict future IDs. This
+    /// bounds that window at the cost of a little throughput. See also [`LID::reseed`] to trigger
+    /// one immediately.
+    #[must_use]
+    pub fn with_reseed_after(reseed_after: u64) -> Self {
+        Self::build(default_alphabet(), R::default(), Some(reseed_after))
+    }
+
+    /// Starts a [`Builder`] for combining a custom alphabet, RNG, and reseed policy in one
+    /// [`LID`], rather than picking a single option via [`LID::with_alphabet`],
+    /// [`LID::with_rng`], or [`LID::with_reseed_after`].
+    #[must_use]
+    pub fn builder() -> Builder<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R> {
+        Builder::new()
+    }
+}
+
+/// Builder for a [`LID`] that combines a custom [`Alphabet`], RNG, and reseed policy, which the
+/// single-option constructors ([`LID::with_alphabet`], [`LID::with_rng`],
+/// [`LID::with_reseed_after`]) can't do together. Obtained via [`LID::builder`].
+///
+/// ```
+/// use lid::{Alphabet, LID};
+/// use rand::rngs::OsRng;
+///
+/// let alphabet = Alphabet::new("0123456789").unwrap();
+/// let mut lid = LID::<12, 8, 100, 1000, OsRng>::builder()
+///     .alphabet(alphabet)
+///     .rng(OsRng)
+///     .reseed_after(10_000)
+///     .build();
+/// println!("{:?}", lid.generate());
+/// ```
+pub struct Builder<
+    const PREFIX_LENGTH: usize,
+    const SEQUENCE_LENGTH: usize,
+    const MIN_INCREMENT: u64,
+    const MAX_INCREMENT: u64,
+    R,
+> {
+    alphabet: Option<Alphabet>,
+    rng: Option<R>,
+    reseed_after: Option<u64>,
+}
+
+impl<
+        const PREFIX_LENGTH: usize,
+        const SEQUENCE_LENGTH: usize,
+        const MIN_INCREMENT: u64,
+        const MAX_INCREMENT: u64,
+        R: RngCore + Default,
+    > Builder<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R>
+{
+    fn new() -> Self {
+        Self {
+            alphabet: None,
+            rng: None,
+            reseed_after: None,
+        }
+    }
+
+    /// Draws prefix and sequence characters from `alphabet` instead of the feature-selected
+    /// [`BASE_ALPHABET`].
+    #[must_use]
+    pub fn alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = Some(alphabet);
+        self
+    }
+
+    /// Backs the built [`LID`] with `rng` instead of `R::default()`.
+    #[must_use]
+    pub fn rng(mut self, rng: R) -> Self {
+        self.rng = Some(rng);
+        self
+    }
+
+    /// Forces a fresh prefix and increment every `reseed_after` generated IDs. See
+    /// [`LID::with_reseed_after`] for why this matters.
+    #[must_use]
+    pub fn reseed_after(mut self, reseed_after: u64) -> Self {
+        self.reseed_after = Some(reseed_after);
+        self
+    }
+
+    /// Assembles the configured [`LID`], falling back to the feature-selected [`BASE_ALPHABET`],
+    /// `R::default()`, and no automatic reseeding for any option that wasn't set.
+    #[must_use]
+    pub fn build(self) -> LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R> {
+        LID::build(
+            self.alphabet.unwrap_or_else(default_alphabet),
+            self.rng.unwrap_or_default(),
+            self.reseed_after,
+        )
+    }
 }
 
 impl<
@@ -228,7 +658,31 @@ impl<
         const SEQUENCE_LENGTH: usize,
         const MIN_INCREMENT: u64,
         const MAX_INCREMENT: u64,
-    > Default for LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT>
+        R: RngCore + SeedableRng,
+    > LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R>
+{
+    /// Creates a new [`LID`] whose RNG is seeded via [`SeedableRng::from_seed`], producing a
+    /// reproducible stream of IDs.
+    #[must_use]
+    pub fn from_seed(seed: R::Seed) -> Self {
+        Self::with_rng(R::from_seed(seed))
+    }
+
+    /// Creates a new [`LID`] whose RNG is seeded from a single `u64`, for convenience when a full
+    /// `R::Seed` is awkward to construct by hand.
+    #[must_use]
+    pub fn seed_from_u64(state: u64) -> Self {
+        Self::with_rng(R::seed_from_u64(state))
+    }
+}
+
+impl<
+        const PREFIX_LENGTH: usize,
+        const SEQUENCE_LENGTH: usize,
+        const MIN_INCREMENT: u64,
+        const MAX_INCREMENT: u64,
+        R: RngCore + Default,
+    > Default for LID<PREFIX_LENGTH, SEQUENCE_LENGTH, MIN_INCREMENT, MAX_INCREMENT, R>
 {
     fn default() -> Self {
         Self::new()
@@ -285,6 +739,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(not(feature = "no-unsafe"))]
+    fn test_batch() {
+        let mut lid = configs::new_distributed();
+
+        let many = lid.generate_many(100);
+        assert_eq!(many.len(), 100);
+        assert_eq!(many.iter().collect::<HashSet<_>>().len(), 100);
+
+        let mut buf = vec![String::new(); 100];
+        lid.fill(&mut buf);
+        assert_eq!(buf.iter().collect::<HashSet<_>>().len(), 100);
+        assert!(many.iter().collect::<HashSet<_>>().is_disjoint(&buf.iter().collect()));
+    }
+
+    #[test]
+    #[cfg(feature = "no-unsafe")]
+    fn test_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let mut lid = configs::new_distributed();
+
+        let many = lid
+            .generate_many(100)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(many.len(), 100);
+        assert_eq!(many.iter().collect::<HashSet<_>>().len(), 100);
+
+        let mut buf = vec![String::new(); 100];
+        lid.fill(&mut buf)?;
+        assert_eq!(buf.iter().collect::<HashSet<_>>().len(), 100);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-unsafe"))]
+    fn test_reseed_after() {
+        let mut lid = LID::<6, 9, 1000, 1_000_000>::with_reseed_after(5);
+        let initial_prefix = lid.prefix.clone();
+
+        for _ in 0..5 {
+            let _ = lid.generate();
+        }
+
+        assert_ne!(
+            lid.prefix, initial_prefix,
+            "prefix should have been forced to reseed after the threshold was reached"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "no-unsafe")]
+    fn test_reseed_after() -> Result<(), Box<dyn std::error::Error>> {
+        let mut lid = LID::<6, 9, 1000, 1_000_000>::with_reseed_after(5);
+        let initial_prefix = lid.prefix.clone();
+
+        for _ in 0..5 {
+            let _ = lid.generate()?;
+        }
+
+        assert_ne!(
+            lid.prefix, initial_prefix,
+            "prefix should have been forced to reseed after the threshold was reached"
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(all(feature = "easy", not(feature = "no-unsafe")))]
     fn test_easy() {
@@ -301,4 +823,65 @@ mod tests {
         let _ = generate_distributed()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg(all(feature = "easy", not(feature = "no-unsafe")))]
+    fn test_easy_local() {
+        use self::easy::{generate_distributed_local, generate_random_local};
+
+        let _ = generate_distributed_local();
+        let _ = generate_random_local();
+    }
+
+    #[test]
+    #[cfg(all(feature = "easy", feature = "no-unsafe"))]
+    fn test_easy_local() -> Result<(), Box<dyn std::error::Error>> {
+        use self::easy::{generate_distributed_local, generate_random_local};
+
+        let _ = generate_distributed_local()?;
+        let _ = generate_random_local()?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-unsafe"))]
+    fn test_seeded_determinism() {
+        use rand::rngs::StdRng;
+
+        let mut a = LID::<12, 8, 100, 1000, StdRng>::seed_from_u64(42);
+        let mut b = LID::<12, 8, 100, 1000, StdRng>::seed_from_u64(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.generate(), b.generate());
+        }
+
+        let mut c = LID::<12, 8, 100, 1000, StdRng>::from_seed([7; 32]);
+        let mut d = LID::<12, 8, 100, 1000, StdRng>::from_seed([7; 32]);
+
+        for _ in 0..100 {
+            assert_eq!(c.generate(), d.generate());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "no-unsafe")]
+    fn test_seeded_determinism() -> Result<(), Box<dyn std::error::Error>> {
+        use rand::rngs::StdRng;
+
+        let mut a = LID::<12, 8, 100, 1000, StdRng>::seed_from_u64(42);
+        let mut b = LID::<12, 8, 100, 1000, StdRng>::seed_from_u64(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.generate()?, b.generate()?);
+        }
+
+        let mut c = LID::<12, 8, 100, 1000, StdRng>::from_seed([7; 32]);
+        let mut d = LID::<12, 8, 100, 1000, StdRng>::from_seed([7; 32]);
+
+        for _ in 0..100 {
+            assert_eq!(c.generate()?, d.generate()?);
+        }
+
+        Ok(())
+    }
 }